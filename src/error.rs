@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// Why a path failed validation.
+///
+/// Returned by the `try_new`/`try_new_for`/`try_join`/`try_push` constructors and the
+/// [`TryFrom`] impls, so callers can return a precise error message instead of a bare `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PathRatchetError {
+    /// The path contains a `..` component that isn't allowed here.
+    ContainsParentDir,
+    /// The path is absolute (starts with a root directory).
+    Absolute,
+    /// The path starts with a prefix, e.g. a Windows drive letter (`C:`) or a UNC share.
+    HasPrefix,
+    /// A single-component path was expected, but the path has more than one component.
+    MultipleComponents,
+    /// The path is empty.
+    Empty,
+    /// The path violates an additional rule of the targeted platform (e.g. a reserved Windows
+    /// device name, a trailing space/dot, or a forbidden character).
+    InvalidForPlatform,
+    /// Joining this path onto a [`RootedPathBuf`](crate::RootedPathBuf) would climb above its
+    /// trusted base directory.
+    Escapes,
+}
+
+impl fmt::Display for PathRatchetError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::ContainsParentDir => "path contains a `..` component",
+            Self::Absolute => "path is absolute",
+            Self::HasPrefix => "path has a prefix (e.g. a Windows drive letter)",
+            Self::MultipleComponents => "path has more than one component",
+            Self::Empty => "path is empty",
+            Self::InvalidForPlatform => "path violates an additional rule of the targeted platform",
+            Self::Escapes => "path would escape its rooted base directory",
+        };
+
+        formatter.write_str(message)
+    }
+}
+
+impl std::error::Error for PathRatchetError {}
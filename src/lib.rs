@@ -130,6 +130,117 @@ macro_rules! impl_conv_traits {
     };
 }
 
+mod error;
+mod rooted;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use error::PathRatchetError;
+pub use rooted::RootedPathBuf;
+
+fn classify_multi(path: &Path) -> Result<(), PathRatchetError> {
+    use std::path::Component;
+
+    for component in path.components() {
+        match component {
+            Component::Normal(_) | Component::CurDir => {}
+            Component::ParentDir => return Err(PathRatchetError::ContainsParentDir),
+            Component::RootDir => return Err(PathRatchetError::Absolute),
+            Component::Prefix(_) => return Err(PathRatchetError::HasPrefix),
+        }
+    }
+
+    Ok(())
+}
+
+fn classify_single(path: &Path) -> Result<(), PathRatchetError> {
+    use std::path::Component;
+
+    classify_multi(path)?;
+
+    let mut normal_components = path
+        .components()
+        .filter(|component| matches!(component, Component::Normal(_)));
+
+    match (normal_components.next(), normal_components.next()) {
+        (Some(_), None) => Ok(()),
+        (None, None) => Err(PathRatchetError::Empty),
+        _ => Err(PathRatchetError::MultipleComponents),
+    }
+}
+
+fn classify_single_for_platform(path: &Path, platform: TargetPlatform) -> Result<(), PathRatchetError> {
+    use std::path::Component;
+
+    classify_single(path)?;
+
+    match platform {
+        TargetPlatform::Unix => Ok(()),
+        TargetPlatform::Windows => {
+            let normal_component = path.components().find_map(|component| match component {
+                Component::Normal(part) => Some(part),
+                _ => None,
+            });
+
+            normal_component
+                .and_then(|part| part.to_str())
+                .is_some_and(is_valid_windows_component)
+                .then_some(())
+                .ok_or(PathRatchetError::InvalidForPlatform)
+        }
+    }
+}
+
+/// The platform whose path rules a component should be validated against.
+///
+/// The crate's own default validation follows whichever platform the code is compiled for, which
+/// means e.g. `C:\path\to\file.txt` validates fine on Unix because the backslashes are treated
+/// as one normal component. `TargetPlatform::Windows` lets a caller apply Windows' rules
+/// regardless of the host, which matters for servers that store paths for clients running a
+/// different OS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TargetPlatform {
+    /// No rules beyond the default validation. That validation parses the path with the host's
+    /// own rules, so on a Windows host `\` still separates components and drive prefixes are
+    /// still rejected; Unix parsing is not emulated there.
+    Unix,
+    /// Windows path rules: additionally rejects drive/UNC prefixes, reserved device names
+    /// (`CON`, `COM1`, ...), trailing dots/spaces, the characters `<>:"|?*`, and control
+    /// characters.
+    Windows,
+}
+
+fn is_valid_windows_component(component: &str) -> bool {
+    const FORBIDDEN_CHARS: [char; 7] = ['<', '>', ':', '"', '|', '?', '*'];
+
+    // A `\` here means the component would actually split into several components on Windows.
+    if component.contains('\\') {
+        return false;
+    }
+
+    if component.ends_with('.') || component.ends_with(' ') {
+        return false;
+    }
+
+    if component.contains(FORBIDDEN_CHARS) || component.chars().any(|char| char.is_control()) {
+        return false;
+    }
+
+    let stem = component.split('.').next().unwrap_or(component);
+    !is_reserved_windows_device_name(stem)
+}
+
+fn is_reserved_windows_device_name(stem: &str) -> bool {
+    const RESERVED_NAMES: &[&str] = &[
+        "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7",
+        "COM8", "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+    ];
+
+    RESERVED_NAMES
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(stem))
+}
+
 /// A safe wrapper for a `PathBuf` with only a single component.
 /// This prevents path traversal attacks.
 ///
@@ -162,16 +273,69 @@ impl SingleComponentPathBuf {
     /// # }
     /// ```
     pub fn new<S: Into<PathBuf>>(component: S) -> Option<Self> {
-        let component = Self {
-            path: component.into(),
-        };
+        Self::try_new(component).ok()
+    }
 
-        component.is_valid().then_some(component)
+    /// Like [`Self::new`], but returns the reason validation failed instead of discarding it.
+    ///
+    /// ```
+    /// use path_ratchet::{PathRatchetError, SingleComponentPathBuf};
+    ///
+    /// assert_eq!(
+    ///     SingleComponentPathBuf::try_new("foo/bar.txt"),
+    ///     Err(PathRatchetError::MultipleComponents)
+    /// );
+    /// ```
+    pub fn try_new<S: Into<PathBuf>>(component: S) -> Result<Self, PathRatchetError> {
+        let path = component.into();
+        classify_single(&path)?;
+
+        Ok(Self { path })
+    }
+
+    /// Like [`Self::new`], but validates against `platform`'s rules instead of the host's.
+    ///
+    /// ```
+    /// use path_ratchet::{SingleComponentPathBuf, TargetPlatform};
+    ///
+    /// assert!(SingleComponentPathBuf::new_for(r"C:\path\to\file.txt", TargetPlatform::Windows).is_none());
+    /// assert!(SingleComponentPathBuf::new_for("CON.txt", TargetPlatform::Windows).is_none());
+    /// assert!(SingleComponentPathBuf::new_for("bar.txt", TargetPlatform::Windows).is_some());
+    /// ```
+    pub fn new_for<S: Into<PathBuf>>(component: S, platform: TargetPlatform) -> Option<Self> {
+        Self::try_new_for(component, platform).ok()
+    }
+
+    /// Like [`Self::new_for`], but returns the reason validation failed instead of discarding it.
+    pub fn try_new_for<S: Into<PathBuf>>(
+        component: S,
+        platform: TargetPlatform,
+    ) -> Result<Self, PathRatchetError> {
+        let path = component.into();
+        classify_single_for_platform(&path, platform)?;
+
+        Ok(Self { path })
     }
 }
 
 impl_buf_traits! {SingleComponentPathBuf}
 
+impl TryFrom<PathBuf> for SingleComponentPathBuf {
+    type Error = PathRatchetError;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        Self::try_new(path)
+    }
+}
+
+impl TryFrom<&str> for SingleComponentPathBuf {
+    type Error = PathRatchetError;
+
+    fn try_from(path: &str) -> Result<Self, Self::Error> {
+        Self::try_new(path)
+    }
+}
+
 /// A safe wrapper for a `Path` with only a single component.
 /// This prevents path traversal attacks.
 ///
@@ -205,29 +369,62 @@ impl SingleComponentPath {
     /// # }
     /// ```
     pub fn new<P: AsRef<Path> + ?Sized>(component: &P) -> Option<&Self> {
+        Self::try_new(component).ok()
+    }
+
+    /// Like [`Self::new`], but returns the reason validation failed instead of discarding it.
+    ///
+    /// ```
+    /// use path_ratchet::{PathRatchetError, SingleComponentPath};
+    ///
+    /// assert_eq!(
+    ///     SingleComponentPath::try_new("foo/bar.txt"),
+    ///     Err(PathRatchetError::MultipleComponents)
+    /// );
+    /// ```
+    pub fn try_new<P: AsRef<Path> + ?Sized>(component: &P) -> Result<&Self, PathRatchetError> {
         let component = wrap_ref_path!(component.as_ref(), Self);
+        classify_single(&component.path)?;
 
-        component.is_valid().then_some(component)
+        Ok(component)
     }
 
-    pub(crate) fn is_valid(&self) -> bool {
-        use std::path::Component;
+    /// Like [`Self::new`], but validates against `platform`'s rules instead of the host's.
+    ///
+    /// ```
+    /// use path_ratchet::{SingleComponentPath, TargetPlatform};
+    ///
+    /// assert!(SingleComponentPath::new_for(r"C:\path\to\file.txt", TargetPlatform::Windows).is_none());
+    /// assert!(SingleComponentPath::new_for("CON.txt", TargetPlatform::Windows).is_none());
+    /// assert!(SingleComponentPath::new_for("bar.txt", TargetPlatform::Windows).is_some());
+    /// ```
+    pub fn new_for<P: AsRef<Path> + ?Sized>(component: &P, platform: TargetPlatform) -> Option<&Self> {
+        Self::try_new_for(component, platform).ok()
+    }
 
-        let mut components = self
-            .path
-            .components()
-            .filter(|component| !matches!(component, Component::CurDir));
+    /// Like [`Self::new_for`], but returns the reason validation failed instead of discarding it.
+    pub fn try_new_for<P: AsRef<Path> + ?Sized>(
+        component: &P,
+        platform: TargetPlatform,
+    ) -> Result<&Self, PathRatchetError> {
+        let component = wrap_ref_path!(component.as_ref(), Self);
+        classify_single_for_platform(&component.path, platform)?;
 
-        matches!(
-            (components.next(), components.next()),
-            (Some(Component::Normal(_)), None)
-        )
+        Ok(component)
     }
 }
 
 impl_ref_path_traits! {SingleComponentPath}
 impl_conv_traits! {SingleComponentPathBuf, SingleComponentPath}
 
+impl<'path> TryFrom<&'path str> for &'path SingleComponentPath {
+    type Error = PathRatchetError;
+
+    fn try_from(path: &'path str) -> Result<Self, Self::Error> {
+        SingleComponentPath::try_new(path)
+    }
+}
+
 /// A safe wrapper for a `PathBuf`.
 /// This prevents path traversal attacks.
 ///
@@ -260,16 +457,45 @@ impl MultiComponentPathBuf {
     /// # }
     /// ```
     pub fn new<S: Into<PathBuf>>(component: S) -> Option<Self> {
-        let component = Self {
-            path: component.into(),
-        };
+        Self::try_new(component).ok()
+    }
 
-        component.is_valid().then_some(component)
+    /// Like [`Self::new`], but returns the reason validation failed instead of discarding it.
+    ///
+    /// ```
+    /// use path_ratchet::{MultiComponentPathBuf, PathRatchetError};
+    ///
+    /// assert_eq!(
+    ///     MultiComponentPathBuf::try_new(".."),
+    ///     Err(PathRatchetError::ContainsParentDir)
+    /// );
+    /// ```
+    pub fn try_new<S: Into<PathBuf>>(component: S) -> Result<Self, PathRatchetError> {
+        let path = component.into();
+        classify_multi(&path)?;
+
+        Ok(Self { path })
     }
 }
 
 impl_buf_traits! {MultiComponentPathBuf}
 
+impl TryFrom<PathBuf> for MultiComponentPathBuf {
+    type Error = PathRatchetError;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        Self::try_new(path)
+    }
+}
+
+impl TryFrom<&str> for MultiComponentPathBuf {
+    type Error = PathRatchetError;
+
+    fn try_from(path: &str) -> Result<Self, Self::Error> {
+        Self::try_new(path)
+    }
+}
+
 /// A safe wrapper for a `Path`.
 /// This prevents path traversal attacks.
 ///
@@ -303,23 +529,79 @@ impl MultiComponentPath {
     /// # }
     /// ```
     pub fn new<P: AsRef<Path> + ?Sized>(component: &P) -> Option<&Self> {
+        Self::try_new(component).ok()
+    }
+
+    /// Like [`Self::new`], but returns the reason validation failed instead of discarding it.
+    ///
+    /// ```
+    /// use path_ratchet::{MultiComponentPath, PathRatchetError};
+    ///
+    /// assert_eq!(
+    ///     MultiComponentPath::try_new(".."),
+    ///     Err(PathRatchetError::ContainsParentDir)
+    /// );
+    /// ```
+    pub fn try_new<P: AsRef<Path> + ?Sized>(component: &P) -> Result<&Self, PathRatchetError> {
         let component = wrap_ref_path!(component.as_ref(), Self);
+        classify_multi(&component.path)?;
 
-        component.is_valid().then_some(component)
+        Ok(component)
     }
 
-    pub(crate) fn is_valid(&self) -> bool {
+    /// Returns an iterator over the normal elements of this path, each wrapped as a
+    /// [`SingleComponentPath`].
+    ///
+    /// `CurDir` (`.`) entries are skipped, so every yielded item is guaranteed to be a single
+    /// normal element, already validated, without re-parsing or re-validating the path.
+    ///
+    /// ```
+    /// use path_ratchet::MultiComponentPath;
+    ///
+    /// # #[cfg(unix)]
+    /// # {
+    /// let path = MultiComponentPath::new("./foo/bar.txt").unwrap();
+    /// let names: Vec<&std::path::Path> = path.components().map(AsRef::as_ref).collect();
+    ///
+    /// assert_eq!(names, [std::path::Path::new("foo"), std::path::Path::new("bar.txt")]);
+    /// # }
+    /// ```
+    pub fn components(&self) -> impl DoubleEndedIterator<Item = &SingleComponentPath> {
         use std::path::Component;
 
-        self.path
-            .components()
-            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        self.path.components().filter_map(|component| match component {
+            Component::Normal(part) => Some(wrap_ref_path!(Path::new(part), SingleComponentPath)),
+            _ => None,
+        })
+    }
+
+    /// Returns the first normal element of this path, if any.
+    pub fn first(&self) -> Option<&SingleComponentPath> {
+        self.components().next()
+    }
+
+    /// Returns the last normal element of this path, if any.
+    pub fn last(&self) -> Option<&SingleComponentPath> {
+        self.components().next_back()
+    }
+
+    /// Returns this path without its last component, if it has one.
+    pub fn parent(&self) -> Option<&Self> {
+        self.path.parent().map(|parent| wrap_ref_path!(parent, Self))
     }
 }
 
 impl_ref_path_traits! {MultiComponentPath}
 impl_conv_traits! {MultiComponentPathBuf, MultiComponentPath}
 
+impl<'path> TryFrom<&'path str> for &'path MultiComponentPath {
+    type Error = PathRatchetError;
+
+    fn try_from(path: &'path str) -> Result<Self, Self::Error> {
+        MultiComponentPath::try_new(path)
+    }
+}
+
 /// Extension trait for [`PathBuf`] to push only components which don't allow path traversal.
 pub trait PushPathComponent {
     /// This allows to push just a [`SingleComponentPathBuf`] to a [`std::path::PathBuf`].
@@ -370,7 +652,12 @@ pub mod prelude {
 
     pub use crate::SingleComponentPath;
     pub use crate::SingleComponentPathBuf;
+    pub use crate::TargetPlatform;
 
     pub use crate::MultiComponentPath;
     pub use crate::MultiComponentPathBuf;
+
+    pub use crate::RootedPathBuf;
+
+    pub use crate::PathRatchetError;
 }
@@ -0,0 +1,97 @@
+use std::ffi::OsString;
+use std::path::{Component, Path, PathBuf};
+
+use crate::PathRatchetError;
+
+/// A path rooted at a trusted base directory, for safely joining untrusted relative paths that
+/// may themselves contain `..`.
+///
+/// Unlike [`MultiComponentPath`](crate::MultiComponentPath), which rejects any `..` outright,
+/// `RootedPathBuf` allows it as long as the resolved path can never climb above the `base` it
+/// was created from. Resolution is purely lexical — it never touches the filesystem — so it
+/// also works for paths that don't exist yet, e.g. when extracting an archive or computing an
+/// upload target.
+///
+/// ```
+/// use std::path::Path;
+/// use path_ratchet::RootedPathBuf;
+///
+/// # #[cfg(unix)]
+/// # {
+/// let root = RootedPathBuf::new("/srv/www");
+/// let safe = root.try_join("images/cat.png").unwrap();
+/// let safe_path: &Path = safe.as_ref();
+/// assert_eq!(safe_path, Path::new("/srv/www/images/cat.png"));
+///
+/// // Allowed: `..` that stays within the root.
+/// let still_safe = safe.try_join("../cat.png").unwrap();
+/// let still_safe_path: &Path = still_safe.as_ref();
+/// assert_eq!(still_safe_path, Path::new("/srv/www/images/cat.png"));
+///
+/// // Rejected: `..` that would escape the root.
+/// assert!(root.try_join("../etc/shadow").is_err());
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct RootedPathBuf {
+    base_len: usize,
+    path: PathBuf,
+}
+
+impl RootedPathBuf {
+    /// Creates a new `RootedPathBuf` rooted at `base`.
+    ///
+    /// `base` itself is trusted and is not validated; it is the prefix that
+    /// [`Self::try_join`]/[`Self::try_push`] will refuse to climb above.
+    pub fn new(base: impl Into<PathBuf>) -> Self {
+        let path = base.into();
+        let base_len = path.components().count();
+
+        Self { base_len, path }
+    }
+
+    /// Lexically joins an untrusted relative `path` onto this one.
+    ///
+    /// Returns [`PathRatchetError::Absolute`]/[`PathRatchetError::HasPrefix`] if `path` is
+    /// absolute or carries a prefix (e.g. a Windows drive letter), or
+    /// [`PathRatchetError::Escapes`] if it contains enough `..` components to climb above the
+    /// base directory.
+    pub fn try_join(&self, path: impl AsRef<Path>) -> Result<Self, PathRatchetError> {
+        let mut components: Vec<OsString> = self
+            .path
+            .components()
+            .map(|component| component.as_os_str().to_os_string())
+            .collect();
+
+        for component in path.as_ref().components() {
+            match component {
+                Component::Normal(part) => components.push(part.to_os_string()),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if components.len() <= self.base_len {
+                        return Err(PathRatchetError::Escapes);
+                    }
+                    components.pop();
+                }
+                Component::RootDir => return Err(PathRatchetError::Absolute),
+                Component::Prefix(_) => return Err(PathRatchetError::HasPrefix),
+            }
+        }
+
+        Ok(Self {
+            base_len: self.base_len,
+            path: components.into_iter().collect(),
+        })
+    }
+
+    /// Lexically pushes an untrusted relative path onto this one.
+    ///
+    /// Equivalent to [`Self::try_join`], kept as a separate method to mirror
+    /// [`PushPathComponent`](crate::PushPathComponent). It does not check that `component` is a
+    /// single element: a path with several components is joined like in [`Self::try_join`].
+    pub fn try_push(&self, component: impl AsRef<Path>) -> Result<Self, PathRatchetError> {
+        self.try_join(component)
+    }
+}
+
+impl_buf_traits! {RootedPathBuf}
@@ -0,0 +1,47 @@
+//! `serde` support, gated behind the `serde` feature.
+//!
+//! Serialization emits the wrapped path as a string. Deserialization routes the incoming value
+//! through the type's existing `new` constructor, so a traversal attempt (`../etc/shadow`, an
+//! absolute path, a Windows drive prefix, ...) fails to deserialize instead of silently producing
+//! a value that bypasses validation.
+//!
+//! [`SingleComponentPath`] and [`MultiComponentPath`] are unsized, so only [`Serialize`] is
+//! implemented for them; deserializing always produces an owned [`SingleComponentPathBuf`] or
+//! [`MultiComponentPathBuf`].
+
+use std::path::PathBuf;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{MultiComponentPath, MultiComponentPathBuf, SingleComponentPath, SingleComponentPathBuf};
+
+macro_rules! impl_serialize_by_path {
+    ($path_wrapper:ty) => {
+        impl Serialize for $path_wrapper {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.path.to_string_lossy().serialize(serializer)
+            }
+        }
+    };
+}
+
+macro_rules! impl_deserialize_via_try_new {
+    ($path_buf:ty) => {
+        impl<'de> Deserialize<'de> for $path_buf {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let path = PathBuf::deserialize(deserializer)?;
+
+                Self::try_new(path).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+impl_serialize_by_path! {SingleComponentPathBuf}
+impl_serialize_by_path! {SingleComponentPath}
+impl_deserialize_via_try_new! {SingleComponentPathBuf}
+
+impl_serialize_by_path! {MultiComponentPathBuf}
+impl_serialize_by_path! {MultiComponentPath}
+impl_deserialize_via_try_new! {MultiComponentPathBuf}
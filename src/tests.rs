@@ -45,3 +45,170 @@ fn multi_strip_current_dir() {
 
     assert_eq!(path, replica_path);
 }
+
+#[test]
+fn rooted_allows_parent_within_root() {
+    let root = RootedPathBuf::new("/srv/www");
+    let joined = root.try_join("folder/../file").unwrap();
+    let joined_path: &std::path::Path = joined.as_ref();
+
+    assert_eq!(joined_path, std::path::Path::new("/srv/www/file"));
+}
+
+#[test]
+fn rooted_rejects_parent_above_root() {
+    let root = RootedPathBuf::new("/srv/www");
+
+    assert_eq!(root.try_join("../etc/shadow"), Err(PathRatchetError::Escapes));
+    assert_eq!(
+        root.try_join("folder/../../etc/shadow"),
+        Err(PathRatchetError::Escapes)
+    );
+}
+
+#[test]
+fn rooted_rejects_absolute() {
+    let root = RootedPathBuf::new("/srv/www");
+
+    assert_eq!(root.try_push("/etc/shadow"), Err(PathRatchetError::Absolute));
+}
+
+#[test]
+fn single_windows_rejects_embedded_backslash() {
+    assert!(SingleComponentPath::new_for(r"C:\path\to\file.txt", TargetPlatform::Windows).is_none());
+}
+
+#[test]
+fn single_windows_rejects_reserved_device_name() {
+    assert!(SingleComponentPathBuf::new_for("CON", TargetPlatform::Windows).is_none());
+    assert!(SingleComponentPathBuf::new_for("con.txt", TargetPlatform::Windows).is_none());
+    assert!(SingleComponentPathBuf::new_for("COM1", TargetPlatform::Windows).is_none());
+}
+
+#[test]
+fn single_windows_rejects_reserved_device_name_with_curdir_prefix() {
+    assert!(SingleComponentPathBuf::new_for("./CON.txt", TargetPlatform::Windows).is_none());
+}
+
+#[test]
+fn single_windows_rejects_trailing_dot_or_space() {
+    assert!(SingleComponentPathBuf::new_for("file.", TargetPlatform::Windows).is_none());
+    assert!(SingleComponentPathBuf::new_for("file ", TargetPlatform::Windows).is_none());
+}
+
+#[test]
+fn single_windows_rejects_forbidden_characters() {
+    assert!(SingleComponentPathBuf::new_for("a<b", TargetPlatform::Windows).is_none());
+    assert!(SingleComponentPathBuf::new_for("a?b", TargetPlatform::Windows).is_none());
+}
+
+#[test]
+fn single_windows_allows_plain_file() {
+    assert!(SingleComponentPathBuf::new_for("bar.txt", TargetPlatform::Windows).is_some());
+}
+
+#[test]
+fn multi_components_skips_current_dir() {
+    let path = MultiComponentPath::new("./foo/./bar.txt").unwrap();
+    let names: Vec<_> = path.components().map(AsRef::<std::path::Path>::as_ref).collect();
+
+    assert_eq!(
+        names,
+        [std::path::Path::new("foo"), std::path::Path::new("bar.txt")]
+    );
+}
+
+#[test]
+fn multi_first_and_last() {
+    let path = MultiComponentPath::new("foo/bar/baz.txt").unwrap();
+
+    assert_eq!(path.first().unwrap().as_ref() as &std::path::Path, std::path::Path::new("foo"));
+    assert_eq!(path.last().unwrap().as_ref() as &std::path::Path, std::path::Path::new("baz.txt"));
+}
+
+#[test]
+fn multi_parent() {
+    let path = MultiComponentPath::new("foo/bar/baz.txt").unwrap();
+    let parent = path.parent().unwrap();
+
+    assert_eq!(parent.as_ref() as &std::path::Path, std::path::Path::new("foo/bar"));
+
+    let top_level = MultiComponentPath::new("foo").unwrap();
+    assert_eq!(
+        top_level.parent().unwrap().as_ref() as &std::path::Path,
+        std::path::Path::new("")
+    );
+}
+
+#[test]
+fn single_try_new_reports_failure_reason() {
+    assert_eq!(
+        SingleComponentPathBuf::try_new("foo/bar.txt"),
+        Err(PathRatchetError::MultipleComponents)
+    );
+    assert_eq!(SingleComponentPathBuf::try_new(".."), Err(PathRatchetError::ContainsParentDir));
+    assert_eq!(SingleComponentPathBuf::try_new(""), Err(PathRatchetError::Empty));
+    assert_eq!(
+        SingleComponentPathBuf::try_new("/etc/shadow"),
+        Err(PathRatchetError::Absolute)
+    );
+}
+
+#[test]
+fn multi_try_new_reports_failure_reason() {
+    assert_eq!(MultiComponentPathBuf::try_new(".."), Err(PathRatchetError::ContainsParentDir));
+    assert_eq!(MultiComponentPathBuf::try_new("/"), Err(PathRatchetError::Absolute));
+}
+
+#[test]
+fn single_try_new_for_reports_platform_failure() {
+    assert_eq!(
+        SingleComponentPathBuf::try_new_for("CON", TargetPlatform::Windows),
+        Err(PathRatchetError::InvalidForPlatform)
+    );
+}
+
+#[test]
+fn single_try_from_str() {
+    let path: SingleComponentPathBuf = "bar.txt".try_into().unwrap();
+    assert_eq!(path, SingleComponentPathBuf::new("bar.txt").unwrap());
+
+    let err: Result<SingleComponentPathBuf, _> = "foo/bar.txt".try_into();
+    assert_eq!(err, Err(PathRatchetError::MultipleComponents));
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use super::*;
+    use serde::de::value::{Error as DeError, StrDeserializer};
+    use serde::de::IntoDeserializer;
+    use serde::Deserialize as _;
+
+    fn deserializer(value: &str) -> StrDeserializer<'_, DeError> {
+        value.into_deserializer()
+    }
+
+    #[test]
+    fn single_deserialize_via_new() {
+        let parsed = SingleComponentPathBuf::deserialize(deserializer("bar.txt")).unwrap();
+
+        assert_eq!(parsed, SingleComponentPathBuf::new("bar.txt").unwrap());
+    }
+
+    #[test]
+    fn single_deserialize_rejects_traversal() {
+        assert!(SingleComponentPathBuf::deserialize(deserializer("../etc/shadow")).is_err());
+    }
+
+    #[test]
+    fn multi_deserialize_via_new() {
+        let parsed = MultiComponentPathBuf::deserialize(deserializer("foo/bar.txt")).unwrap();
+
+        assert_eq!(parsed, MultiComponentPathBuf::new("foo/bar.txt").unwrap());
+    }
+
+    #[test]
+    fn multi_deserialize_rejects_absolute() {
+        assert!(MultiComponentPathBuf::deserialize(deserializer("/etc/shadow")).is_err());
+    }
+}